@@ -0,0 +1,169 @@
+use inflections::Inflect;
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+/// Derives a `to_markdown(&self) -> String` method that documents a struct's
+/// fields using the same conventions `MarkdownRenderer` uses for JSON: each
+/// named field becomes a `- **Field Name**` entry, with the field's `///`
+/// doc comment as its description.
+///
+/// Field headers are offset by `#[markdown(indent_headers = N)]` on the
+/// struct, or, if that's absent, by the deepest `#`-header found in the
+/// struct's own doc comment, so generated docs slot under a hand-written
+/// intro.
+///
+/// Individual fields can opt out with `#[markdown(skip)]`, override their
+/// title with `#[markdown(rename = "...")]`, or recurse into a nested
+/// `ToMarkdown` struct with `#[markdown(nested)]`.
+#[proc_macro_derive(ToMarkdown, attributes(markdown))]
+pub fn derive_to_markdown(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    &input.ident,
+                    "ToMarkdown can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into();
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "ToMarkdown can only be derived for structs",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let indent_headers = explicit_indent_headers(&input.attrs)
+        .unwrap_or_else(|| deepest_header_depth(&doc_lines(&input.attrs)));
+    let header_marker = "#".repeat(indent_headers + 1);
+
+    let mut field_tokens = Vec::new();
+
+    for field in fields {
+        if has_flag(&field.attrs, "skip") {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field has an ident");
+        let title =
+            rename(&field.attrs).unwrap_or_else(|| ident.to_string().to_title_case());
+        let description = doc_lines(&field.attrs).join(" ");
+
+        if has_flag(&field.attrs, "nested") {
+            field_tokens.push(quote! {
+                output.push_str(&format!("{} {}\n\n", #header_marker, #title));
+                output.push_str(&self.#ident.to_markdown());
+                output.push('\n');
+            });
+        } else {
+            field_tokens.push(quote! {
+                output.push_str(&format!("- **{}**", #title));
+
+                if !#description.is_empty() {
+                    output.push_str(&format!(": {}", #description));
+                }
+
+                output.push('\n');
+            });
+        }
+    }
+
+    let name = &input.ident;
+
+    let expanded = quote! {
+        impl #name {
+            /// Renders this struct's fields as a Markdown reference section,
+            /// generated by `#[derive(ToMarkdown)]`.
+            #[must_use]
+            pub fn to_markdown(&self) -> String {
+                let mut output = String::new();
+
+                #(#field_tokens)*
+
+                output
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Collects a field or struct's `///` doc comment, one entry per line.
+fn doc_lines(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta().ok()? {
+            Meta::NameValue(nv) => match nv.lit {
+                Lit::Str(s) => Some(s.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Computes the deepest leading-`#` run across a doc comment's header lines.
+fn deepest_header_depth(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let hashes = line.chars().take_while(|c| *c == '#').count();
+            (hashes > 0).then_some(hashes)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the `#[markdown(...)]` attribute's parsed items, if any.
+fn markdown_meta_items(attrs: &[syn::Attribute]) -> Vec<NestedMeta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("markdown"))
+        .filter_map(|attr| match attr.parse_meta().ok()? {
+            Meta::List(list) => Some(list.nested.into_iter().collect::<Vec<_>>()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Whether `#[markdown(flag)]` is present (e.g. `skip`, `nested`).
+fn has_flag(attrs: &[syn::Attribute], flag: &str) -> bool {
+    markdown_meta_items(attrs)
+        .iter()
+        .any(|item| matches!(item, NestedMeta::Meta(Meta::Path(p)) if p.is_ident(flag)))
+}
+
+/// The value of `#[markdown(rename = "...")]`, if present.
+fn rename(attrs: &[syn::Attribute]) -> Option<String> {
+    markdown_meta_items(attrs).into_iter().find_map(|item| match item {
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => match nv.lit {
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// The value of `#[markdown(indent_headers = N)]`, if present.
+fn explicit_indent_headers(attrs: &[syn::Attribute]) -> Option<usize> {
+    markdown_meta_items(attrs).into_iter().find_map(|item| match item {
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("indent_headers") => {
+            match nv.lit {
+                Lit::Int(i) => i.base10_parse::<usize>().ok(),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
@@ -3,6 +3,13 @@ use inflections::Inflect;
 use once_cell::sync::Lazy;
 use serde_json::{Map, Value};
 use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Write};
+
+/// Derives a `to_markdown(&self) -> String` method that documents a struct's
+/// fields using this crate's Markdown conventions. See the
+/// `json2arkdown-derive` crate for the attributes it supports.
+pub use json2arkdown_derive::ToMarkdown;
 
 /// A static cached regex that splits at a period only if itâ€™s followed by whitespace
 /// that is not immediately followed by an uppercase letter and a dot.
@@ -12,6 +19,112 @@ static SPLIT_REGEX: Lazy<Regex> = Lazy::new(|| {
         .unwrap_or_else(|e| panic!("regex failed to build, error: {e}"))
 });
 
+/// Selects how (or whether) the reserved front-matter key is emitted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FrontMatterFormat {
+    /// Emit the reserved key's object as a YAML `--- ... ---` front-matter block.
+    #[default]
+    Yaml,
+    /// Emit the reserved key's object as a TOML `--- ... ---` front-matter block.
+    Toml,
+    /// Don't treat the reserved key specially; render it like any other section.
+    None,
+}
+
+/// Selects the marker style used when rendering JSON arrays as Markdown lists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ListStyle {
+    /// Render list items with a `-` marker.
+    #[default]
+    Unordered,
+    /// Render list items with an incrementing `1.`, `2.`, ... marker.
+    Ordered,
+}
+
+/// Error produced by [`MarkdownRenderer::parse`] when a line of input
+/// doesn't match any of the shapes `render` produces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The line's leading spaces aren't a multiple of `indent_spaces`.
+    UnalignedIndent { line: usize, spaces: usize },
+    /// The line is indented deeper than any open object or list expects.
+    UnexpectedIndent { line: usize },
+    /// The line isn't a header, a `- **Key**: value` / `- **Key**` /
+    /// `- value` bullet, or blank. Also returned, with `text` describing the
+    /// problem, for an object entry inside a list (or a list item inside an
+    /// object) and for a front-matter fence that is never closed.
+    UnrecognizedLine { line: usize, text: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnalignedIndent { line, spaces } => write!(
+                f,
+                "line {line}: indented by {spaces} spaces, which isn't a multiple of indent_spaces"
+            ),
+            Self::UnexpectedIndent { line } => write!(
+                f,
+                "line {line}: indented deeper than any open object or list expects"
+            ),
+            Self::UnrecognizedLine { line, text } => {
+                write!(
+                    f,
+                    "line {line}: not a header, bullet, or blank line: {text:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Tracks the item counter for each nesting depth of an ordered list.
+///
+/// A new depth level is pushed when descending into a nested array and
+/// popped on the way back out, so a sub-list's counter never leaks into its
+/// parent's.
+#[derive(Debug, Default)]
+struct ListNesting {
+    style: ListStyle,
+    counters: Vec<usize>,
+}
+
+impl ListNesting {
+    const fn new(style: ListStyle) -> Self {
+        Self {
+            style,
+            counters: Vec::new(),
+        }
+    }
+
+    /// Enters a new nesting level, starting its counter at zero.
+    fn push(&mut self) {
+        self.counters.push(0);
+    }
+
+    /// Leaves the current nesting level, resuming the parent's counter.
+    fn pop(&mut self) {
+        self.counters.pop();
+    }
+
+    /// Returns the marker for the next item at the current depth, advancing
+    /// the counter for ordered lists.
+    fn next_marker(&mut self, indent: &str) -> String {
+        match self.style {
+            ListStyle::Ordered => {
+                let counter = self
+                    .counters
+                    .last_mut()
+                    .expect("next_marker called without a matching push");
+                *counter += 1;
+                format!("{indent}{counter}. ")
+            }
+            ListStyle::Unordered => format!("{indent}- "),
+        }
+    }
+}
+
 /// Enum to represent different Markdown rendering styles.
 #[derive(Clone, Copy, Debug)]
 enum RenderStyle {
@@ -27,12 +140,27 @@ enum RenderStyle {
     NestedItem,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct MarkdownRenderer {
     /// Number of spaces used for indentation in the rendered Markdown.
     indent_spaces: usize,
     /// Increment in depth for nested structures.
     depth_increment: usize,
+    /// Top-level key that, when present, is emitted as a front-matter block
+    /// instead of a regular section.
+    front_matter_key: String,
+    /// Format used to emit the front-matter block, or `None` to disable the feature.
+    front_matter_format: FrontMatterFormat,
+    /// Marker style used when rendering JSON arrays as Markdown lists.
+    list_style: ListStyle,
+    /// Number of leading `#`s used for the root object's section headers.
+    base_header_level: usize,
+    /// Whether to explode long strings into one paragraph per sentence.
+    split_sentences: bool,
+    /// Token rendered in place of a JSON `null`.
+    null_token: String,
+    /// Whether object keys are passed through `to_title_case`.
+    title_case_keys: bool,
 }
 
 impl Default for MarkdownRenderer {
@@ -40,6 +168,13 @@ impl Default for MarkdownRenderer {
         Self {
             indent_spaces: 1,
             depth_increment: 2,
+            front_matter_key: "front_matter".to_string(),
+            front_matter_format: FrontMatterFormat::Yaml,
+            list_style: ListStyle::Unordered,
+            base_header_level: 2,
+            split_sentences: true,
+            null_token: "N/A".to_string(),
+            title_case_keys: true,
         }
     }
 }
@@ -55,37 +190,174 @@ impl MarkdownRenderer {
     /// # Examples
     ///
     /// ```
+    /// use json2arkdown::MarkdownRenderer;
+    ///
     /// let renderer = MarkdownRenderer::new(1, 2);
     /// ```
     #[must_use]
-    pub const fn new(indent_spaces: usize, depth_increment: usize) -> Self {
+    pub fn new(indent_spaces: usize, depth_increment: usize) -> Self {
         MarkdownRenderer {
             indent_spaces,
             depth_increment,
+            ..Self::default()
         }
     }
 
+    /// Sets the reserved top-level key whose object value is emitted as a
+    /// front-matter block rather than a regular section.
+    ///
+    /// Defaults to `"front_matter"`.
+    #[must_use]
+    pub fn with_front_matter_key(mut self, key: impl Into<String>) -> Self {
+        self.front_matter_key = key.into();
+        self
+    }
+
+    /// Sets the format used to emit the front-matter block. Use
+    /// [`FrontMatterFormat::None`] to disable the feature entirely.
+    #[must_use]
+    pub const fn with_front_matter_format(mut self, format: FrontMatterFormat) -> Self {
+        self.front_matter_format = format;
+        self
+    }
+
+    /// Sets the marker style used when rendering JSON arrays as Markdown lists.
+    ///
+    /// Defaults to [`ListStyle::Unordered`].
+    #[must_use]
+    pub const fn with_list_style(mut self, style: ListStyle) -> Self {
+        self.list_style = style;
+        self
+    }
+
+    /// Sets the number of leading `#`s used for the root object's section
+    /// headers (subsections get one more). Defaults to `2` (`## `, `### `).
+    #[must_use]
+    pub const fn with_base_header_level(mut self, level: usize) -> Self {
+        self.base_header_level = level;
+        self
+    }
+
+    /// Toggles exploding long strings into one paragraph per sentence.
+    /// Defaults to `true`; set to `false` to leave strings intact.
+    #[must_use]
+    pub const fn with_split_sentences(mut self, enabled: bool) -> Self {
+        self.split_sentences = enabled;
+        self
+    }
+
+    /// Sets the token rendered in place of a JSON `null`. Defaults to `"N/A"`.
+    #[must_use]
+    pub fn with_null_token(mut self, token: impl Into<String>) -> Self {
+        self.null_token = token.into();
+        self
+    }
+
+    /// Toggles passing object keys through `to_title_case`. Defaults to `true`.
+    #[must_use]
+    pub const fn with_title_case_keys(mut self, enabled: bool) -> Self {
+        self.title_case_keys = enabled;
+        self
+    }
+
+    /// Returns a [`MarkdownRendererBuilder`] for configuring a renderer one
+    /// option at a time.
+    #[must_use]
+    pub fn builder() -> MarkdownRendererBuilder {
+        MarkdownRendererBuilder::default()
+    }
+
     /// Renders a JSON value into a Markdown string.
     ///
     /// # Arguments
     ///
     /// * `json` - The JSON value to render.
     ///
-    /// # Errors
-    ///
-    ///
     /// # Examples
     ///
     /// ```
+    /// use json2arkdown::MarkdownRenderer;
+    ///
     /// let renderer = MarkdownRenderer::new(1, 2);
     /// let json = serde_json::json!({"title": "My Document"});
     /// let markdown = renderer.render(&json);
     /// ```
     #[must_use]
     pub fn render(&self, json: &Value) -> String {
-        let mut output = String::with_capacity(4096); // Pre-allocate memory for large JSON
-        self.render_value(json, 0, RenderStyle::Root, &mut output, false);
-        output
+        let mut output = Vec::with_capacity(4096); // Pre-allocate memory for large JSON
+        self.render_to(json, &mut output)
+            .expect("writing to an in-memory buffer cannot fail");
+        String::from_utf8(output).expect("renderer only ever writes valid UTF-8")
+    }
+
+    /// Renders a JSON value as Markdown directly into `writer`, without
+    /// buffering the whole document in memory first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use json2arkdown::MarkdownRenderer;
+    ///
+    /// let renderer = MarkdownRenderer::new(1, 2);
+    /// let json = serde_json::json!({"title": "My Document"});
+    /// let mut out = Vec::new();
+    /// renderer.render_to(&json, &mut out).unwrap();
+    /// ```
+    pub fn render_to<W: Write>(&self, json: &Value, writer: &mut W) -> io::Result<()> {
+        if self.front_matter_format != FrontMatterFormat::None {
+            if let Value::Object(obj) = json {
+                if let Some(front_matter) = obj.get(&self.front_matter_key) {
+                    self.render_front_matter(front_matter, writer)?;
+
+                    let mut rest = obj.clone();
+                    rest.remove(&self.front_matter_key);
+                    return self.render_value(
+                        &Value::Object(rest),
+                        0,
+                        RenderStyle::Root,
+                        writer,
+                        false,
+                    );
+                }
+            }
+        }
+
+        self.render_value(json, 0, RenderStyle::Root, writer, false)
+    }
+
+    /// Renders the reserved front-matter key's object as a fenced `---` block
+    /// using `self.front_matter_format`.
+    ///
+    /// Front-matter values are expected to be scalars. `yaml_scalar`/
+    /// `toml_scalar` fall back to `Display` for an array or object, and
+    /// `parse_front_matter` reads that fallback text back with
+    /// [`parse_scalar`]/[`parse_toml_scalar`], which have no array/object case
+    /// of their own — so a non-scalar front-matter value comes back as the
+    /// string its `Display` impl produced, not the original array or object.
+    fn render_front_matter<W: Write>(&self, value: &Value, output: &mut W) -> io::Result<()> {
+        let Value::Object(obj) = value else {
+            return Ok(());
+        };
+
+        output.write_all(b"---\n")?;
+
+        for (key, value) in obj {
+            match self.front_matter_format {
+                FrontMatterFormat::Yaml => {
+                    writeln!(output, "{key}: {}", yaml_scalar(value))?;
+                }
+                FrontMatterFormat::Toml => {
+                    writeln!(output, "{key} = {}", toml_scalar(value))?;
+                }
+                FrontMatterFormat::None => {}
+            }
+        }
+
+        output.write_all(b"---\n\n")
     }
 
     /// Handles rendering of different JSON values based on their type.
@@ -96,67 +368,73 @@ impl MarkdownRenderer {
     /// * `depth` - Current depth level in the hierarchy.
     /// * `style` - Current rendering style.
     /// * `output` - The output buffer to write the rendered Markdown.
-    fn render_value(
+    fn render_value<W: Write>(
         &self,
         value: &Value,
         depth: usize,
         style: RenderStyle,
-        output: &mut String,
+        output: &mut W,
         written_before: bool,
-    ) {
+    ) -> io::Result<()> {
         match value {
             Value::Object(obj) => self.render_object(obj, depth, style, output),
-            Value::Array(arr) => self.render_array(arr, depth, style, output),
+            Value::Array(arr) => self.render_array(arr, depth, output),
             Value::String(s) => format_value(s, style, output, written_before),
             Value::Number(n) => format_value(&n.to_string(), style, output, written_before),
             Value::Bool(b) => format_value(&b.to_string(), style, output, written_before),
-            Value::Null => format_value("N/A", style, output, written_before),
+            Value::Null => format_value(&self.null_token, style, output, written_before),
         }
     }
 
-    fn render_object(
+    fn render_object<W: Write>(
         &self,
         obj: &Map<String, Value>,
         depth: usize,
         style: RenderStyle,
-        output: &mut String,
-    ) {
+        output: &mut W,
+    ) -> io::Result<()> {
         let indent = self.get_indent(depth);
 
         for (key, value) in obj {
-            let (new_style, header_marker, depth_increment) = match (depth, style) {
-                (0, RenderStyle::Root) => (RenderStyle::Section, "## ", 0),
-                (1, RenderStyle::Section) => (RenderStyle::Subsection, "### ", 0),
-                _ => (RenderStyle::ListItem, "", self.depth_increment),
+            // Dispatched on `style` alone (not `depth`, which only tracks
+            // bullet indentation): headers nest two levels deep regardless
+            // of how many indentation levels that spans.
+            let (new_style, header_marker, depth_increment) = match style {
+                RenderStyle::Root => (RenderStyle::Section, "#".repeat(self.base_header_level), 0),
+                RenderStyle::Section => (
+                    RenderStyle::Subsection,
+                    "#".repeat(self.base_header_level + 1),
+                    0,
+                ),
+                _ => (RenderStyle::ListItem, String::new(), self.depth_increment),
             };
 
-            let formatted_key = match new_style {
+            match new_style {
                 RenderStyle::Section | RenderStyle::Subsection => {
-                    format!("{indent}{header_marker}{}\n\n", key.to_title_case())
+                    write!(
+                        output,
+                        "{indent}{header_marker} {}\n\n",
+                        self.format_key(key)
+                    )?;
                 }
-                RenderStyle::ListItem => format!("{indent}- **{}**", key.to_title_case()),
-                _ => key.to_title_case(),
-            };
-
-            output.push_str(&formatted_key);
+                RenderStyle::ListItem => {
+                    write!(output, "{indent}- **{}**", self.format_key(key))?;
+                }
+                _ => write!(output, "{}", self.format_key(key))?,
+            }
 
             match value {
                 Value::Object(inner_obj) if !inner_obj.is_empty() => {
-                    output.push_str("\n\n");
-                    self.render_object(inner_obj, depth + depth_increment, new_style, output);
+                    output.write_all(b"\n\n")?;
+                    self.render_object(inner_obj, depth + depth_increment, new_style, output)?;
                 }
                 Value::Array(arr) if !arr.is_empty() => {
-                    output.push_str("\n\n");
-                    self.render_array(
-                        arr,
-                        depth + depth_increment,
-                        RenderStyle::NestedItem,
-                        output,
-                    );
-                    output.push_str("\n\n");
+                    output.write_all(b"\n\n")?;
+                    self.render_array(arr, depth + depth_increment, output)?;
+                    output.write_all(b"\n\n")?;
                 }
                 Value::String(value) => {
-                    output.push_str("\n\n");
+                    output.write_all(b"\n\n")?;
 
                     // we don't touch it if it's a url
                     let s = if value.starts_with("http") {
@@ -175,76 +453,344 @@ impl MarkdownRenderer {
                         }
                     };
 
-                    output.push_str(&s);
-                    output.push('\n');
+                    writeln!(output, "{s}")?;
                 }
                 _ => {
+                    // Section/Subsection already ended the key's line with
+                    // "\n\n", so the value starts its own paragraph with no
+                    // ": " joiner; ListItem wrote "- **Key**" with no
+                    // newline yet, so the value continues that same line.
+                    let written_before = matches!(new_style, RenderStyle::ListItem);
                     self.render_value(
                         value,
                         depth + depth_increment,
-                        RenderStyle::NestedItem,
+                        new_style,
                         output,
-                        true,
-                    );
+                        written_before,
+                    )?;
                 }
             }
         }
+
+        Ok(())
+    }
+
+    fn render_array<W: Write>(
+        &self,
+        arr: &[Value],
+        depth: usize,
+        output: &mut W,
+    ) -> io::Result<()> {
+        let mut nesting = ListNesting::new(self.list_style);
+        self.render_array_nested(arr, depth, output, &mut nesting)
     }
 
-    fn render_array(&self, arr: &[Value], depth: usize, style: RenderStyle, output: &mut String) {
+    /// Renders a JSON array as a Markdown list, sharing `nesting` with any
+    /// sub-arrays so ordered-list counters reset on entry and resume on exit.
+    fn render_array_nested<W: Write>(
+        &self,
+        arr: &[Value],
+        depth: usize,
+        output: &mut W,
+        nesting: &mut ListNesting,
+    ) -> io::Result<()> {
         let indent = self.get_indent(depth);
+        nesting.push();
 
         for item in arr {
-            let marker = match style {
-                RenderStyle::NestedItem => "  - ",
-                _ => "- ",
-            };
-
-            // we only want to do a '-' if it's not an object or an array
-            let mut do_hyphen = || output.push_str(&format!("{indent}{marker}"));
-
             match item {
+                // A bare `- ` marker of its own, with the object's/array's
+                // own bullets one level deeper, so `parse` can tell where
+                // this element ends and the next one begins — without it,
+                // consecutive object elements are indistinguishable from
+                // one bigger object, and consecutive nested arrays from one
+                // flattened list (see `parse`'s handling of an empty
+                // `BulletBare` followed by deeper content).
                 Value::Object(obj) if !obj.is_empty() => {
+                    output.write_all(nesting.next_marker(&indent).as_bytes())?;
+                    output.write_all(b"\n\n")?;
                     self.render_object(
                         obj,
                         depth + self.depth_increment,
                         RenderStyle::NestedItem,
                         output,
-                    );
+                    )?;
                 }
                 Value::Array(inner_arr) if !inner_arr.is_empty() => {
-                    self.render_array(
+                    output.write_all(nesting.next_marker(&indent).as_bytes())?;
+                    output.write_all(b"\n\n")?;
+                    self.render_array_nested(
                         inner_arr,
                         depth + self.depth_increment,
-                        RenderStyle::NestedItem,
                         output,
-                    );
+                        nesting,
+                    )?;
                 }
                 Value::String(s) => {
-                    do_hyphen();
-                    output.push_str(&format!("{s}\n"));
+                    output.write_all(nesting.next_marker(&indent).as_bytes())?;
+                    writeln!(output, "{s}")?;
                 }
                 _ => {
-                    do_hyphen();
+                    output.write_all(nesting.next_marker(&indent).as_bytes())?;
                     self.render_value(
                         item,
                         depth + self.depth_increment,
                         RenderStyle::NestedItem,
                         output,
                         false,
-                    );
+                    )?;
                 }
             }
         }
+
+        nesting.pop();
+        Ok(())
+    }
+
+    /// Parses Markdown produced by `render`/`render_to` back into a
+    /// `serde_json::Value`, using the same indentation and key-casing
+    /// settings that drove rendering.
+    ///
+    /// `## ` / `### ` headers open nested objects keyed by the de-title-cased
+    /// heading, `- **Key**: value` lines become scalar object entries, `-
+    /// **Key**` lines open a nested object or list depending on what follows,
+    /// and bare `- value` bullets accumulate into a list. A leading `---
+    /// ... ---` fence is parsed back into `front_matter_key` when
+    /// `front_matter_format` isn't [`FrontMatterFormat::None`].
+    ///
+    /// # Known limitations
+    ///
+    /// When `split_sentences` is enabled (the default), `render` discards
+    /// the period at each sentence break instead of re-emitting it, so a
+    /// multi-sentence string value does not round-trip byte-for-byte
+    /// through `render` then `parse` — only the sentence boundaries move
+    /// to separate lines. Disable `split_sentences` if you need strings to
+    /// round-trip exactly.
+    ///
+    /// Every scalar value outside the front-matter fence is rendered as bare
+    /// text and read back by the same rules: `null_token` becomes `null`,
+    /// `true`/`false` become booleans, and anything parseable as a number
+    /// becomes one. So a string whose contents happen to look like a
+    /// number, `true`/`false`, or the configured `null_token` comes back as
+    /// that type instead of a string — the Markdown carries no quoting to
+    /// tell the two apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if a line's indentation isn't a multiple of
+    /// `indent_spaces`, is deeper than any open object or list expects, or
+    /// doesn't match a recognized shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use json2arkdown::MarkdownRenderer;
+    ///
+    /// let renderer = MarkdownRenderer::new(1, 2);
+    /// let json = serde_json::json!({"title": "My Document"});
+    /// let markdown = renderer.render(&json);
+    /// assert_eq!(renderer.parse(&markdown).unwrap(), json);
+    /// ```
+    pub fn parse(&self, markdown: &str) -> Result<Value, ParseError> {
+        let mut lines = markdown.lines().enumerate().peekable();
+
+        let front_matter = if self.front_matter_format != FrontMatterFormat::None
+            && lines.peek().is_some_and(|&(_, line)| line.trim() == "---")
+        {
+            Some(self.parse_front_matter(&mut lines)?)
+        } else {
+            None
+        };
+
+        let mut stack = vec![ParseFrame::root()];
+
+        while let Some((index, raw_line)) = lines.next() {
+            let line = index + 1;
+
+            if raw_line.trim().is_empty() {
+                continue;
+            }
+
+            let spaces = raw_line.len() - raw_line.trim_start_matches(' ').len();
+            if spaces % self.indent_spaces != 0 {
+                return Err(ParseError::UnalignedIndent { line, spaces });
+            }
+            let indent = spaces / self.indent_spaces;
+            let trimmed = raw_line.trim_start_matches(' ');
+
+            match ParseLine::parse(trimmed) {
+                ParseLine::Header { level, text } => {
+                    ascend_for_header(&mut stack, level);
+                    let key = self.parse_key(text);
+
+                    if let Some(text) = take_prose(&mut lines) {
+                        stack
+                            .last_mut()
+                            .expect("root frame is never popped")
+                            .as_object_mut(line)?
+                            .insert(key, self.parse_scalar(&text));
+                    } else {
+                        stack.push(ParseFrame::header(key, level));
+                    }
+                }
+                ParseLine::BulletKeyValue { key, value } => {
+                    ascend_to_indent(&mut stack, line, indent)?;
+                    let frame = stack.last_mut().expect("root frame is never popped");
+                    frame.children_indent.get_or_insert(indent);
+                    frame
+                        .as_object_mut(line)?
+                        .insert(self.parse_key(key), self.parse_scalar(value));
+                }
+                ParseLine::BulletKey { key } => {
+                    ascend_to_indent(&mut stack, line, indent)?;
+                    {
+                        let frame = stack.last_mut().expect("root frame is never popped");
+                        frame.children_indent.get_or_insert(indent);
+                    }
+                    let key = self.parse_key(key);
+
+                    if let Some(text) = take_prose(&mut lines) {
+                        stack
+                            .last_mut()
+                            .expect("root frame is never popped")
+                            .as_object_mut(line)?
+                            .insert(key, self.parse_scalar(&text));
+                    } else {
+                        stack
+                            .last_mut()
+                            .expect("root frame is never popped")
+                            .as_object_mut(line)?;
+                        stack.push(ParseFrame::child(Some(key)));
+                    }
+                }
+                ParseLine::BulletBare { value } => {
+                    let jumped = ascend_for_bare_bullet(&mut stack, line, indent)?;
+
+                    // An empty bare bullet followed by more-indented content
+                    // is the marker `render_array_nested` writes in front of
+                    // an object/array list element (see its own doc
+                    // comment); a lone empty-string element never has
+                    // deeper content of its own, so this can't misfire. Skip
+                    // it if `ascend_for_bare_bullet` already opened a frame
+                    // for this same line's indent jump (see its doc comment).
+                    let opens_element = !jumped
+                        && value.is_empty()
+                        && peek_content_indent(&mut lines, self.indent_spaces) > Some(indent);
+
+                    let frame = stack.last_mut().expect("root frame is never popped");
+                    frame.children_indent.get_or_insert(indent);
+
+                    if opens_element {
+                        frame.as_array_mut(line)?;
+                        stack.push(ParseFrame::child(None));
+                    } else {
+                        frame.as_array_mut(line)?.push(self.parse_scalar(value));
+                    }
+                }
+                ParseLine::Unrecognized => {
+                    return Err(ParseError::UnrecognizedLine {
+                        line,
+                        text: trimmed.to_string(),
+                    });
+                }
+            }
+        }
+
+        while stack.len() > 1 {
+            close_frame(&mut stack);
+        }
+
+        let mut root = stack
+            .pop()
+            .expect("root frame always present")
+            .container
+            .into_value();
+        if let (Some(front_matter), Value::Object(obj)) = (front_matter, &mut root) {
+            obj.insert(self.front_matter_key.clone(), Value::Object(front_matter));
+        }
+
+        Ok(root)
+    }
+
+    /// Consumes a leading `--- ... ---` fence as a flat map of scalar values,
+    /// using `self.front_matter_format` to pick the `key: value` vs.
+    /// `key = value` separator.
+    fn parse_front_matter<'a>(
+        &self,
+        lines: &mut std::iter::Peekable<impl Iterator<Item = (usize, &'a str)>>,
+    ) -> Result<Map<String, Value>, ParseError> {
+        lines.next(); // the opening "---"
+
+        let mut front_matter = Map::new();
+
+        for (index, raw_line) in lines.by_ref() {
+            let line = index + 1;
+
+            if raw_line.trim() == "---" {
+                return Ok(front_matter);
+            }
+
+            let (separator, parse_value): (&str, fn(&str) -> Value) = match self.front_matter_format
+            {
+                FrontMatterFormat::Yaml => (": ", |v| parse_scalar(v, "null")),
+                FrontMatterFormat::Toml => (" = ", parse_toml_scalar),
+                FrontMatterFormat::None => unreachable!("checked by the caller"),
+            };
+
+            let Some((key, value)) = raw_line.split_once(separator) else {
+                return Err(ParseError::UnrecognizedLine {
+                    line,
+                    text: raw_line.to_string(),
+                });
+            };
+
+            front_matter.insert(key.to_string(), parse_value(value));
+        }
+
+        Err(ParseError::UnrecognizedLine {
+            line: lines.peek().map_or(0, |&(index, _)| index + 1),
+            text: "unterminated front-matter fence".to_string(),
+        })
+    }
+
+    /// Converts a title-cased `key` back to the snake-case form JSON objects use, unless
+    /// `self.title_case_keys` is disabled, in which case headings and bullet
+    /// keys were left untouched by `render` too.
+    fn parse_key(&self, key: &str) -> String {
+        if self.title_case_keys {
+            key.to_snake_case()
+        } else {
+            key.to_string()
+        }
+    }
+
+    /// Parses a bare scalar the way `render` would have formatted it: `self
+    /// .null_token` becomes `null`, `true`/`false` become booleans, numbers
+    /// parse as numbers, and anything else is a string.
+    fn parse_scalar(&self, value: &str) -> Value {
+        parse_scalar(value, &self.null_token)
     }
 
     fn get_indent(&self, depth: usize) -> String {
         " ".repeat(depth * self.indent_spaces)
     }
 
+    /// Title-cases `key` if `self.title_case_keys` is set, otherwise returns it unchanged.
+    fn format_key<'a>(&self, key: &'a str) -> Cow<'a, str> {
+        if self.title_case_keys {
+            Cow::Owned(key.to_title_case())
+        } else {
+            Cow::Borrowed(key)
+        }
+    }
+
     /// splits the strings at '.' and adds 2 new lines for readability, we return the given if
     /// there is no '.'
     fn split_at_period<'a>(&self, text: &'a str, depth: usize) -> Cow<'a, str> {
+        if !self.split_sentences {
+            return Cow::Borrowed(text);
+        }
+
         let indent = self.get_indent(depth);
 
         if !SPLIT_REGEX.is_match(text).is_ok_and(|b| b) {
@@ -257,31 +803,603 @@ impl MarkdownRenderer {
             .collect::<Result<Vec<_>, _>>()
             .unwrap_or_else(|e| panic!("regex failed to split, error: {e}"));
 
-        let capacity = (splitted.len() * indent.len() + 4) + text.len();
+        Cow::Owned(splitted.into_iter().fold(String::new(), |mut acc, part| {
+            acc.push_str(&indent);
+            acc.push_str(part.trim());
+            acc.push_str("\n\n");
+            acc
+        }))
+    }
+}
+
+/// Chainable builder for a [`MarkdownRenderer`], useful when configuring
+/// several options at once instead of calling `with_*` one at a time.
+#[derive(Clone, Debug, Default)]
+pub struct MarkdownRendererBuilder {
+    renderer: MarkdownRenderer,
+}
+
+impl MarkdownRendererBuilder {
+    /// Creates a builder pre-populated with `MarkdownRenderer`'s defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// See [`MarkdownRenderer::new`].
+    #[must_use]
+    pub fn indent_spaces(mut self, indent_spaces: usize) -> Self {
+        self.renderer.indent_spaces = indent_spaces;
+        self
+    }
+
+    /// See [`MarkdownRenderer::new`].
+    #[must_use]
+    pub fn depth_increment(mut self, depth_increment: usize) -> Self {
+        self.renderer.depth_increment = depth_increment;
+        self
+    }
 
-        Cow::Owned(
-            splitted
-                .into_iter()
-                .fold(String::with_capacity(capacity), |mut acc, part| {
-                    acc.push_str(&indent);
-                    acc.push_str(part.trim());
-                    acc.push_str("\n\n");
-                    acc
-                }),
-        )
+    /// See [`MarkdownRenderer::with_front_matter_key`].
+    #[must_use]
+    pub fn front_matter_key(mut self, key: impl Into<String>) -> Self {
+        self.renderer = self.renderer.with_front_matter_key(key);
+        self
+    }
+
+    /// See [`MarkdownRenderer::with_front_matter_format`].
+    #[must_use]
+    pub fn front_matter_format(mut self, format: FrontMatterFormat) -> Self {
+        self.renderer = self.renderer.with_front_matter_format(format);
+        self
+    }
+
+    /// See [`MarkdownRenderer::with_list_style`].
+    #[must_use]
+    pub fn list_style(mut self, style: ListStyle) -> Self {
+        self.renderer = self.renderer.with_list_style(style);
+        self
+    }
+
+    /// See [`MarkdownRenderer::with_base_header_level`].
+    #[must_use]
+    pub fn base_header_level(mut self, level: usize) -> Self {
+        self.renderer = self.renderer.with_base_header_level(level);
+        self
+    }
+
+    /// See [`MarkdownRenderer::with_split_sentences`].
+    #[must_use]
+    pub fn split_sentences(mut self, enabled: bool) -> Self {
+        self.renderer = self.renderer.with_split_sentences(enabled);
+        self
+    }
+
+    /// See [`MarkdownRenderer::with_null_token`].
+    #[must_use]
+    pub fn null_token(mut self, token: impl Into<String>) -> Self {
+        self.renderer = self.renderer.with_null_token(token);
+        self
+    }
+
+    /// See [`MarkdownRenderer::with_title_case_keys`].
+    #[must_use]
+    pub fn title_case_keys(mut self, enabled: bool) -> Self {
+        self.renderer = self.renderer.with_title_case_keys(enabled);
+        self
+    }
+
+    /// Finishes configuration and returns the built [`MarkdownRenderer`].
+    #[must_use]
+    pub fn build(self) -> MarkdownRenderer {
+        self.renderer
     }
 }
 
-fn format_value(value: &str, style: RenderStyle, output: &mut String, written_before: bool) {
+/// Formats a scalar JSON value as a bare YAML value.
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "null".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Formats a scalar JSON value as a bare TOML value.
+fn toml_scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("{s:?}"),
+        Value::Null => "\"\"".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn format_value<W: Write>(
+    value: &str,
+    style: RenderStyle,
+    output: &mut W,
+    written_before: bool,
+) -> io::Result<()> {
     // we don't want to do ": " if there is nothing before
     let before_value = if written_before { ": " } else { "" };
 
     match style {
         RenderStyle::ListItem | RenderStyle::NestedItem => {
-            output.push_str(&format!("{before_value}{value}\n"));
+            writeln!(output, "{before_value}{value}")
         }
         RenderStyle::Root | RenderStyle::Section | RenderStyle::Subsection => {
-            output.push_str(&format!("{before_value}{value}\n\n"));
+            write!(output, "{before_value}{value}\n\n")
+        }
+    }
+}
+
+/// Parses a bare scalar the way `render`/`format_value` would have formatted
+/// it: `null_token` becomes `null`, `true`/`false` become booleans, numbers
+/// parse as numbers, and anything else is a string.
+fn parse_scalar(value: &str, null_token: &str) -> Value {
+    if value == null_token {
+        Value::Null
+    } else if value == "true" {
+        Value::Bool(true)
+    } else if value == "false" {
+        Value::Bool(false)
+    } else if let Ok(n) = value.parse::<i64>() {
+        Value::Number(n.into())
+    } else if let Ok(f) = value.parse::<f64>() {
+        serde_json::Number::from_f64(f)
+            .map_or_else(|| Value::String(value.to_string()), Value::Number)
+    } else {
+        Value::String(value.to_string())
+    }
+}
+
+/// Parses a bare scalar the way `toml_scalar` would have formatted it:
+/// quoted strings have their `\"` and `\\` escapes undone, `""` is `null`,
+/// and everything else follows [`parse_scalar`]'s rules. Other escapes that
+/// `toml_scalar`'s `{:?}` formatting writes (such as `\n` or `\u{..}`) are
+/// left as written.
+fn parse_toml_scalar(value: &str) -> Value {
+    if value == "\"\"" {
+        return Value::Null;
+    }
+
+    if let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        return Value::String(inner.replace("\\\"", "\"").replace("\\\\", "\\"));
+    }
+
+    parse_scalar(value, "")
+}
+
+/// Looks past any blank lines for plain prose immediately following a line
+/// that opened a key (a header or a `- **Key**` bullet) — the shape `render`
+/// uses for string values, which are written as their own paragraph rather
+/// than inline after a `: `. Consumes and joins that prose if found; leaves
+/// the iterator untouched and returns `None` if the next non-blank line is a
+/// header or bullet instead.
+fn take_prose<'a>(
+    lines: &mut std::iter::Peekable<impl Iterator<Item = (usize, &'a str)>>,
+) -> Option<String> {
+    let mut parts = Vec::new();
+
+    while let Some(&(_, next)) = lines.peek() {
+        if next.trim().is_empty() {
+            lines.next();
+            continue;
+        }
+
+        if matches!(
+            ParseLine::parse(next.trim_start_matches(' ')),
+            ParseLine::Unrecognized
+        ) {
+            let (_, text) = lines.next().expect("peek just confirmed a line is present");
+            parts.push(text.trim().to_string());
+        } else {
+            break;
         }
     }
+
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+/// Skips any blank lines and returns the indent level of the next line,
+/// without consuming it. Blank lines *are* consumed either way, same as the
+/// main loop in `parse` would have discarded them itself.
+fn peek_content_indent<'a>(
+    lines: &mut std::iter::Peekable<impl Iterator<Item = (usize, &'a str)>>,
+    indent_spaces: usize,
+) -> Option<usize> {
+    while lines.peek().is_some_and(|&(_, line)| line.trim().is_empty()) {
+        lines.next();
+    }
+
+    lines.peek().map(|&(_, line)| {
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        spaces / indent_spaces
+    })
+}
+
+/// A line of rendered Markdown, classified into the shape `render` would
+/// have produced it as.
+enum ParseLine<'a> {
+    /// A `#`-prefixed header opening a nested object, keyed by `text`.
+    Header { level: usize, text: &'a str },
+    /// A `- **Key**: value` object entry.
+    BulletKeyValue { key: &'a str, value: &'a str },
+    /// A `- **Key**` line whose value is a nested object or list on
+    /// subsequent, more-indented lines.
+    BulletKey { key: &'a str },
+    /// A bare `- value` list item.
+    BulletBare { value: &'a str },
+    /// A line that doesn't match any of the above.
+    Unrecognized,
+}
+
+impl<'a> ParseLine<'a> {
+    /// Classifies an already-indent-stripped line.
+    fn parse(line: &'a str) -> Self {
+        if let Some(text) = line.strip_prefix('#') {
+            let rest = text.trim_start_matches('#');
+            let level = line.len() - rest.len();
+            if let Some(text) = rest.strip_prefix(' ') {
+                return Self::Header { level, text };
+            }
+            return Self::Unrecognized;
+        }
+
+        if let Some(value) = Self::strip_ordered_marker(line) {
+            return Self::BulletBare { value };
+        }
+
+        let Some(rest) = line.strip_prefix("- ") else {
+            return Self::Unrecognized;
+        };
+
+        let Some(bold) = rest.strip_prefix("**") else {
+            return Self::BulletBare { value: rest };
+        };
+
+        let Some((key, remainder)) = bold.split_once("**") else {
+            return Self::Unrecognized;
+        };
+
+        if remainder.is_empty() {
+            Self::BulletKey { key }
+        } else if let Some(value) = remainder.strip_prefix(": ") {
+            Self::BulletKeyValue { key, value }
+        } else {
+            Self::Unrecognized
+        }
+    }
+
+    /// Strips a [`ListStyle::Ordered`] marker (`"1. "`, `"2. "`, ...) off the
+    /// front of `line`, since `ListNesting::next_marker` doesn't hard-code
+    /// `"- "` the way a plain list does.
+    fn strip_ordered_marker(line: &'a str) -> Option<&'a str> {
+        let digits = line.find(|c: char| !c.is_ascii_digit()).unwrap_or(0);
+        if digits == 0 {
+            return None;
+        }
+
+        line[digits..].strip_prefix(". ")
+    }
+}
+
+/// The JSON shape a [`ParseFrame`] is building: resolved to an object or
+/// list by its first child line, or still unknown for a freshly opened
+/// `- **Key**` frame that has no children yet.
+enum ParseContainer {
+    Object(Map<String, Value>),
+    Array(Vec<Value>),
+    Unknown,
+}
+
+impl ParseContainer {
+    fn into_value(self) -> Value {
+        match self {
+            Self::Object(map) => Value::Object(map),
+            Self::Array(items) => Value::Array(items),
+            Self::Unknown => Value::Object(Map::new()),
+        }
+    }
+}
+
+/// One level of the container stack `MarkdownRenderer::parse` maintains
+/// while walking the Markdown line by line.
+struct ParseFrame {
+    /// The key this frame is stored under in its parent object, or `None`
+    /// for the root frame and for nested-list frames (stored as plain array
+    /// elements instead).
+    key: Option<String>,
+    /// `Some(level)` for a frame opened by a `#`-header; headers are only
+    /// closed by another header at the same or shallower level, never by a
+    /// bullet's indentation.
+    header_level: Option<usize>,
+    /// The indentation level (in `indent_spaces` units) this frame's direct
+    /// children appear at, fixed by whichever child line arrives first.
+    children_indent: Option<usize>,
+    container: ParseContainer,
+}
+
+impl ParseFrame {
+    fn root() -> Self {
+        Self {
+            key: None,
+            header_level: Some(0),
+            children_indent: None,
+            container: ParseContainer::Object(Map::new()),
+        }
+    }
+
+    /// A frame opened by a `#`-header. Its shape isn't known until its first
+    /// child line arrives: a header's value can be a nested object (more
+    /// `- **Key**` entries) just as easily as a list (bare bullets).
+    fn header(key: String, level: usize) -> Self {
+        Self {
+            key: Some(key),
+            header_level: Some(level),
+            children_indent: None,
+            container: ParseContainer::Unknown,
+        }
+    }
+
+    /// A frame opened by a `- **Key**` line, whose shape isn't known until
+    /// its first child line arrives.
+    fn child(key: Option<String>) -> Self {
+        Self {
+            key,
+            header_level: None,
+            children_indent: None,
+            container: ParseContainer::Unknown,
+        }
+    }
+
+    fn as_object_mut(&mut self, line: usize) -> Result<&mut Map<String, Value>, ParseError> {
+        if matches!(self.container, ParseContainer::Unknown) {
+            self.container = ParseContainer::Object(Map::new());
+        }
+
+        match &mut self.container {
+            ParseContainer::Object(map) => Ok(map),
+            ParseContainer::Array(_) | ParseContainer::Unknown => {
+                Err(ParseError::UnrecognizedLine {
+                    line,
+                    text: "expected an object entry inside a list".to_string(),
+                })
+            }
+        }
+    }
+
+    fn as_array_mut(&mut self, line: usize) -> Result<&mut Vec<Value>, ParseError> {
+        if matches!(self.container, ParseContainer::Unknown) {
+            self.container = ParseContainer::Array(Vec::new());
+        }
+
+        match &mut self.container {
+            ParseContainer::Array(items) => Ok(items),
+            ParseContainer::Object(_) | ParseContainer::Unknown => {
+                Err(ParseError::UnrecognizedLine {
+                    line,
+                    text: "expected a list item inside an object".to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// Closes the top frame, attaching its value to its parent: by key if it's
+/// an object entry, or pushed as the next element if it's a nested list.
+///
+/// A parent whose only children are nested header frames (never a direct
+/// bullet line) never goes through `as_object_mut`/`as_array_mut`, so its
+/// shape is resolved here too, the same lazy way, from the closing child's
+/// key.
+fn close_frame(stack: &mut Vec<ParseFrame>) {
+    let frame = stack
+        .pop()
+        .expect("close_frame called with a frame to close");
+    let value = frame.container.into_value();
+    let parent = stack.last_mut().expect("root frame is never closed");
+
+    if matches!(parent.container, ParseContainer::Unknown) {
+        parent.container = if frame.key.is_some() {
+            ParseContainer::Object(Map::new())
+        } else {
+            ParseContainer::Array(Vec::new())
+        };
+    }
+
+    match (frame.key, &mut parent.container) {
+        (Some(key), ParseContainer::Object(map)) => {
+            map.insert(key, value);
+        }
+        (None, ParseContainer::Array(items)) => {
+            items.push(value);
+        }
+        _ => unreachable!("a frame's key always matches its parent container's shape"),
+    }
+}
+
+/// Closes any frames a new header line ends: every open bullet frame, and
+/// any open header frame at the same or a deeper level.
+fn ascend_for_header(stack: &mut Vec<ParseFrame>, level: usize) {
+    while stack.len() > 1 {
+        let top = stack.last().expect("root frame is never popped");
+        match top.header_level {
+            Some(top_level) if top_level < level => break,
+            _ => close_frame(stack),
+        }
+    }
+}
+
+/// Closes bullet frames that a keyed/object bullet line at `indent` has
+/// dedented past. Errors if `indent` is deeper than the current frame
+/// expects, since only a nested-list frame can absorb an over-indented
+/// bare bullet (see [`ascend_for_bare_bullet`]).
+fn ascend_to_indent(
+    stack: &mut Vec<ParseFrame>,
+    line: usize,
+    indent: usize,
+) -> Result<(), ParseError> {
+    loop {
+        let top = stack.last().expect("root frame is never popped");
+
+        match top.children_indent {
+            None => return Ok(()),
+            Some(child_indent) if child_indent == indent => return Ok(()),
+            Some(child_indent) if child_indent > indent && top.header_level.is_none() => {
+                close_frame(stack);
+            }
+            _ => return Err(ParseError::UnexpectedIndent { line }),
+        }
+    }
+}
+
+/// Like [`ascend_to_indent`], but a bare bullet indented deeper than the
+/// current list expects opens an unkeyed nested-list frame instead of
+/// erroring. `render` always gives a nested list its own owning empty
+/// bullet first (see `render_array_nested`), so this exists for
+/// hand-written Markdown that skips it and nests bare bullets directly.
+///
+/// Returns whether this call pushed a fresh frame to host the jump itself,
+/// so the caller can skip its own empty-bullet lookahead for this line —
+/// otherwise a hand-written deeper empty bullet would open two nested
+/// frames instead of one, since both this jump and that lookahead read the
+/// same indent increase as "a new list starts here".
+fn ascend_for_bare_bullet(
+    stack: &mut Vec<ParseFrame>,
+    line: usize,
+    indent: usize,
+) -> Result<bool, ParseError> {
+    loop {
+        let top = stack.last().expect("root frame is never popped");
+
+        match top.children_indent {
+            None => return Ok(false),
+            Some(child_indent) if child_indent == indent => return Ok(false),
+            Some(child_indent) if child_indent > indent => {
+                if top.header_level.is_some() {
+                    return Err(ParseError::UnexpectedIndent { line });
+                }
+                close_frame(stack);
+            }
+            Some(_) => {
+                // child_indent < indent: a deeper bare bullet with no owning
+                // empty marker of its own opens a nested-list frame inline,
+                // for hand-written Markdown (see this function's doc comment).
+                stack.push(ParseFrame::child(None));
+                return Ok(true);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn round_trip(renderer: &MarkdownRenderer, value: Value) {
+        let markdown = renderer.render(&value);
+        assert_eq!(
+            renderer.parse(&markdown).unwrap(),
+            value,
+            "did not round-trip:\n{markdown}"
+        );
+    }
+
+    #[test]
+    fn round_trips_root_level_scalars() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        round_trip(
+            &renderer,
+            json!({"a": 1, "b": true, "c": false, "d": Value::Null}),
+        );
+    }
+
+    #[test]
+    fn round_trips_nested_scalars() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        round_trip(
+            &renderer,
+            json!({"outer": {"a": 1, "b": true, "c": Value::Null, "d": "text"}}),
+        );
+    }
+
+    #[test]
+    fn round_trips_unordered_list() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        round_trip(&renderer, json!({"tags": ["rust", "json", "markdown"]}));
+    }
+
+    #[test]
+    fn round_trips_ordered_list() {
+        let renderer = MarkdownRenderer::new(1, 2).with_list_style(ListStyle::Ordered);
+        round_trip(&renderer, json!({"steps": ["first", "second", "third"]}));
+    }
+
+    #[test]
+    fn round_trips_array_of_objects() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        round_trip(
+            &renderer,
+            json!({"e": [{"f": "g", "x": 1}, {"h": "i", "y": false}]}),
+        );
+    }
+
+    #[test]
+    fn round_trips_array_of_arrays() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        round_trip(&renderer, json!({"e": [[1, 2], [3, 4]]}));
+        round_trip(&renderer, json!({"e": [[1, 2]]}));
+    }
+
+    #[test]
+    fn round_trips_yaml_front_matter() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        round_trip(
+            &renderer,
+            json!({"front_matter": {"draft": false, "count": 3}, "title": "Hello"}),
+        );
+    }
+
+    #[test]
+    fn round_trips_toml_front_matter() {
+        let renderer =
+            MarkdownRenderer::new(1, 2).with_front_matter_format(FrontMatterFormat::Toml);
+        round_trip(
+            &renderer,
+            json!({"front_matter": {"draft": false, "count": 3}, "title": "Hello"}),
+        );
+    }
+
+    #[test]
+    fn parse_reports_unaligned_indent() {
+        let renderer = MarkdownRenderer::new(2, 2);
+        let err = renderer.parse("## A\n\n - **B**: 1\n").unwrap_err();
+        assert_eq!(err, ParseError::UnalignedIndent { line: 3, spaces: 1 });
+    }
+
+    #[test]
+    fn parse_reports_unexpected_indent() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        let err = renderer
+            .parse("## A\n\n- **B**: 1\n    - **C**: 2\n")
+            .unwrap_err();
+        assert_eq!(err, ParseError::UnexpectedIndent { line: 4 });
+    }
+
+    #[test]
+    fn parse_reports_unrecognized_line() {
+        let renderer = MarkdownRenderer::new(1, 2);
+        let err = renderer
+            .parse("not a header or bullet **at all**\n")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::UnrecognizedLine {
+                line: 1,
+                text: "not a header or bullet **at all**".to_string(),
+            }
+        );
+    }
 }